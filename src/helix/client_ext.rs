@@ -0,0 +1,140 @@
+//! Convenience functions for [`HelixClient`]
+//!
+//! These are inherent methods on [`HelixClient`] that wrap the most commonly used requests so
+//! callers don't need to construct a request struct themselves, mirroring the `get_user_from_login`
+//! style ergonomic helpers used elsewhere in this crate.
+use super::{games, subscriptions, ClientRequestError, HelixClient, RequestGet};
+use crate::types;
+use futures::Stream;
+use std::collections::VecDeque;
+use twitch_oauth2::TwitchToken;
+
+impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
+    /// Get a [game](games::Game) by its id
+    pub async fn get_game_from_id(
+        &'a self,
+        id: impl Into<types::CategoryId>,
+        token: &impl TwitchToken,
+    ) -> Result<Option<games::Game>, ClientRequestError<C::Error>> {
+        let req = games::get_games::GetGamesRequest::builder()
+            .id(vec![id.into()])
+            .build();
+        Ok(self.req_get(req, token).await?.data.into_iter().next())
+    }
+
+    /// Get a [game](games::Game) by its name
+    pub async fn get_game_from_name(
+        &'a self,
+        name: impl Into<String>,
+        token: &impl TwitchToken,
+    ) -> Result<Option<games::Game>, ClientRequestError<C::Error>> {
+        let req = games::get_games::GetGamesRequest::builder()
+            .name(vec![name.into()])
+            .build();
+        Ok(self.req_get(req, token).await?.data.into_iter().next())
+    }
+
+    /// Get games sorted by number of current viewers on Twitch, most popular first.
+    pub fn get_top_games(
+        &'a self,
+        token: &'a impl TwitchToken,
+    ) -> impl Stream<Item = Result<games::Game, ClientRequestError<C::Error>>> + 'a {
+        let req = games::get_top_games::GetTopGamesRequest::builder().build();
+        self.make_stream(req, token, VecDeque::from)
+    }
+
+    /// Check if a user is subscribed to a broadcaster
+    pub async fn check_user_subscription(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        user_id: impl Into<types::UserId>,
+        token: &impl TwitchToken,
+    ) -> Result<Option<subscriptions::UserSubscription>, ClientRequestError<C::Error>> {
+        use subscriptions::check_user_subscription::CheckUserSubscriptionRequest;
+
+        let req = CheckUserSubscriptionRequest::builder()
+            .broadcaster_id(broadcaster_id.into())
+            .user_id(user_id.into())
+            .build();
+        let http_request = req.create_request(token.token().secret(), token.client_id().as_str())?;
+        let uri = http_request.uri().clone();
+        let response = self
+            .client
+            .req(http_request)
+            .await
+            .map_err(ClientRequestError::RequestError)?;
+        Ok(CheckUserSubscriptionRequest::parse_response(req, &uri, response)?.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A [`crate::HttpClient`] that always answers with a fixed status and body.
+    struct MockHttpClient {
+        status: http::StatusCode,
+        body: &'static str,
+    }
+
+    impl<'a> crate::HttpClient<'a> for MockHttpClient {
+        type Error = std::convert::Infallible;
+
+        fn req(
+            &'a self,
+            _request: http::Request<Vec<u8>>,
+        ) -> futures::future::BoxFuture<'a, Result<http::Response<Vec<u8>>, Self::Error>> {
+            let response = http::Response::builder()
+                .status(self.status)
+                .body(self.body.as_bytes().to_vec())
+                .unwrap();
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    async fn dummy_token() -> impl TwitchToken {
+        let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+        twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_game_from_id_returns_first_match() {
+        let client = HelixClient::new(MockHttpClient {
+            status: http::StatusCode::OK,
+            body: r#"{"data":[{"id":"33214","name":"Fortnite","box_art_url":""}]}"#,
+        });
+        let game = client
+            .get_game_from_id("33214", &dummy_token().await)
+            .await
+            .unwrap();
+        assert_eq!(game.unwrap().id.to_string(), "33214");
+    }
+
+    #[tokio::test]
+    async fn get_game_from_name_returns_none_when_no_match() {
+        let client = HelixClient::new(MockHttpClient {
+            status: http::StatusCode::OK,
+            body: r#"{"data":[]}"#,
+        });
+        let game = client
+            .get_game_from_name("doesnotexist", &dummy_token().await)
+            .await
+            .unwrap();
+        assert!(game.is_none());
+    }
+
+    #[tokio::test]
+    async fn check_user_subscription_maps_404_to_none() {
+        let client = HelixClient::new(MockHttpClient {
+            status: http::StatusCode::NOT_FOUND,
+            body: r#"{"error":"Not Found","status":404,"message":"user is not subscribed"}"#,
+        });
+        let subscription = client
+            .check_user_subscription("1234", "5678", &dummy_token().await)
+            .await
+            .unwrap();
+        assert!(subscription.is_none());
+    }
+}
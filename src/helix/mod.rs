@@ -0,0 +1,434 @@
+//! Helix endpoints, the new Twitch API
+//!
+//! # Implementing your own endpoint
+//!
+//! Implement [`Request`] and [`RequestGet`] for your struct, see the existing
+//! endpoints (e.g. [`games`]) for examples.
+
+use futures::Stream;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::VecDeque;
+
+pub mod client_ext;
+pub mod games;
+pub mod subscriptions;
+
+/// A request, used to create a [`http::Request`] and parse a [`http::Response`].
+///
+/// Implemented for each helix endpoint request struct, see [module level docs](self) for more
+/// information.
+pub trait Request: DeserializeOwned + Serialize + PartialEq {
+    /// The response of this request
+    type Response: DeserializeOwned + PartialEq;
+    /// The path this request points to, without the leading `helix/`.
+    const PATH: &'static str;
+    /// Scopes needed by this request
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope];
+}
+
+/// A request that can be issued as a GET request.
+pub trait RequestGet: Request {
+    /// Create a [`http::Uri`] for this request.
+    fn get_uri(&self) -> Result<http::Uri, InvalidUriError> {
+        let mut uri = format!("https://api.twitch.tv/helix/{}?", Self::PATH);
+        let query = serde_urlencoded::to_string(self)?;
+        uri.push_str(&query);
+        Ok(uri.parse()?)
+    }
+
+    /// Create a [`http::Request`] for this request, to be sent with a http client.
+    fn create_request(
+        &self,
+        token: &str,
+        client_id: &str,
+    ) -> Result<http::Request<Vec<u8>>, CreateRequestError> {
+        let uri = self.get_uri()?;
+        http::Request::builder()
+            .method(http::Method::GET)
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Client-Id", client_id)
+            .body(Vec::new())
+            .map_err(Into::into)
+    }
+
+    /// Parse a [`http::Response`] into the [`Response`] for this request.
+    fn parse_response(
+        self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<Response<Self, Self::Response>, HelixRequestGetError>
+    where Self: Sized {
+        let text = std::str::from_utf8(response.body())
+            .map_err(|e| HelixRequestGetError::Utf8Error(response.body().clone(), e, uri.clone()))?;
+        let inner: InnerResponse<Self::Response> = serde_json::from_str(text)
+            .map_err(|e| HelixRequestGetError::DeserializeError(text.to_owned(), e, uri.clone()))?;
+        Ok(Response {
+            data: inner.data,
+            pagination: inner.pagination.and_then(|p| p.cursor),
+            total: inner.total,
+            other: inner.other,
+            request: self,
+        })
+    }
+}
+
+/// A request that can be paginated, with [`Paginated::set_pagination`] telling the request
+/// where to continue from.
+pub trait Paginated: Request {
+    /// Set the pagination cursor on this request.
+    fn set_pagination(&mut self, cursor: Option<Cursor>);
+}
+
+/// A cursor for pagination, given in [`Response::pagination`] and consumed by
+/// [`Paginated::set_pagination`].
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Get the cursor as a `&str`
+    pub fn as_str(&self) -> &str { &self.0 }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { self.0.fmt(f) }
+}
+
+#[derive(Deserialize)]
+struct Pagination {
+    #[serde(default)]
+    cursor: Option<Cursor>,
+}
+
+/// The raw shape of a Helix response envelope, before being split into [`Response`].
+#[derive(Deserialize)]
+struct InnerResponse<D> {
+    data: D,
+    #[serde(default)]
+    pagination: Option<Pagination>,
+    /// Some endpoints (e.g. Get Broadcaster Subscriptions) return a `total` alongside `data`.
+    #[serde(default)]
+    total: Option<i64>,
+    /// Anything else the endpoint sent that isn't `data`, `pagination` or `total`, e.g. the
+    /// broadcaster's subscriber `points`.
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Response retrieved from a GET request, see [`RequestGet::parse_response`].
+#[derive(PartialEq, Debug)]
+pub struct Response<R, D> {
+    /// The parsed data of this response.
+    pub data: D,
+    /// The cursor used to fetch the next page, if any.
+    pub pagination: Option<Cursor>,
+    /// The `total` field, if the endpoint returns one (e.g. subscriber count).
+    pub total: Option<i64>,
+    /// Any fields returned by the endpoint that aren't part of `data`, `pagination` or `total`.
+    /// Empty if the endpoint didn't return any.
+    pub other: serde_json::Map<String, serde_json::Value>,
+    /// The request that was issued to get this response.
+    pub request: R,
+}
+
+impl<R, D> Response<R, D> {
+    /// Get a field from [`Response::other`] by key.
+    ///
+    /// Useful for endpoints that return extra top-level fields not covered by `data`, such as
+    /// the broadcaster's subscriber [`points`](Response::points) total.
+    pub fn get_other(&self, key: &str) -> Option<&serde_json::Value> { self.other.get(key) }
+
+    /// Get the broadcaster's subscriber `points` total, if the endpoint returned one.
+    ///
+    /// Returned by e.g. [Get Broadcaster
+    /// Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions).
+    pub fn points(&self) -> Option<i64> { self.get_other("points").and_then(|v| v.as_i64()) }
+}
+
+/// A client for the Helix API.
+pub struct HelixClient<'a, C>
+where C: crate::HttpClient<'a> {
+    client: C,
+    _pd: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a, C: crate::HttpClient<'a> + Default> Default for HelixClient<'a, C> {
+    fn default() -> Self { Self::new(C::default()) }
+}
+
+impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
+    /// Create a new [`HelixClient`] with the given http client.
+    pub fn new(client: C) -> Self {
+        HelixClient {
+            client,
+            _pd: std::marker::PhantomData,
+        }
+    }
+
+    /// Request on a valid [`RequestGet`] endpoint
+    pub async fn req_get<R, D>(
+        &'a self,
+        request: R,
+        token: &impl twitch_oauth2::TwitchToken,
+    ) -> Result<Response<R, D>, ClientRequestError<C::Error>>
+    where
+        R: Request<Response = D> + RequestGet,
+        D: DeserializeOwned + PartialEq,
+    {
+        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        let uri = req.uri().clone();
+        let response = self
+            .client
+            .req(req)
+            .await
+            .map_err(ClientRequestError::RequestError)?;
+        request.parse_response(&uri, response).map_err(Into::into)
+    }
+
+    /// Turn a [`Paginated`] [`RequestGet`] request into a [`Stream`] of the individual items
+    /// held by each page.
+    ///
+    /// See [`make_stream`] for more information.
+    pub fn make_stream<R, D, T>(
+        &'a self,
+        request: R,
+        token: &'a impl twitch_oauth2::TwitchToken,
+        make_items: impl Fn(R::Response) -> VecDeque<T> + 'a + Send + Sync,
+    ) -> impl Stream<Item = Result<T, ClientRequestError<C::Error>>> + 'a
+    where
+        R: Request<Response = D> + RequestGet + Paginated + Clone + Send + Sync + 'a,
+        D: DeserializeOwned + PartialEq + Send + Sync + 'a,
+        T: 'a,
+    {
+        make_stream(request, token, self, make_items)
+    }
+}
+
+/// Turn a [`Paginated`] [`RequestGet`] request into a [`Stream`] of the individual items held
+/// by each page.
+///
+/// The stream yields items out of an internal buffer; once the buffer is empty, the request is
+/// cloned, [`Paginated::set_pagination`] is called with the cursor from the previous page, and
+/// the next page is fetched. The stream ends once the cursor is `None` or a page maps to no
+/// items.
+///
+/// ```rust, no_run
+/// use twitch_api2::helix::{self, games::get_top_games};
+/// use futures::TryStreamExt;
+/// # use twitch_api2::client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+/// let req = get_top_games::GetTopGamesRequest::builder().build();
+/// let games: Vec<_> =
+///     helix::make_stream(req, &token, &client, std::collections::VecDeque::from)
+///         .try_collect()
+///         .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn make_stream<'a, R, D, T, C>(
+    request: R,
+    token: &'a impl twitch_oauth2::TwitchToken,
+    client: &'a HelixClient<'a, C>,
+    make_items: impl Fn(R::Response) -> VecDeque<T> + 'a + Send + Sync,
+) -> impl Stream<Item = Result<T, ClientRequestError<C::Error>>> + 'a
+where
+    R: Request<Response = D> + RequestGet + Paginated + Clone + Send + Sync + 'a,
+    D: DeserializeOwned + PartialEq + Send + Sync + 'a,
+    T: 'a,
+    C: crate::HttpClient<'a>,
+{
+    enum State<R, T> {
+        /// Fetch `R`, it hasn't been requested yet.
+        Fetch(R),
+        /// Yield buffered items, fetching `R` again once empty (`None` means pagination is
+        /// exhausted).
+        Buffered(Option<R>, VecDeque<T>),
+    }
+
+    let make_items = std::sync::Arc::new(make_items);
+    futures::stream::unfold(State::Fetch(request), move |mut state| {
+        // Clone the `Arc` here so the `async move` block below owns its own handle to
+        // `make_items`; a reference into the `FnMut` closure's captures could not escape
+        // the closure body in the returned future.
+        let make_items = make_items.clone();
+        async move {
+            loop {
+                match state {
+                    State::Fetch(request) => {
+                        let response = match client.req_get(request.clone(), token).await {
+                            Ok(response) => response,
+                            Err(e) => return Some((Err(e), State::Buffered(None, VecDeque::new()))),
+                        };
+                        let cursor = response.pagination;
+                        let buffer = make_items(response.data);
+                        if buffer.is_empty() {
+                            return None;
+                        }
+                        let next_request = cursor.map(|cursor| {
+                            let mut request = request;
+                            request.set_pagination(Some(cursor));
+                            request
+                        });
+                        state = State::Buffered(next_request, buffer);
+                    }
+                    State::Buffered(request, mut buffer) => {
+                        if let Some(item) = buffer.pop_front() {
+                            return Some((Ok(item), State::Buffered(request, buffer)));
+                        }
+                        match request {
+                            Some(request) => state = State::Fetch(request),
+                            None => return None,
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+    struct DummyRequest {
+        after: Option<Cursor>,
+    }
+
+    impl Request for DummyRequest {
+        type Response = Vec<i32>;
+
+        const PATH: &'static str = "dummy";
+        #[cfg(feature = "twitch_oauth2")]
+        const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+    }
+
+    impl RequestGet for DummyRequest {}
+
+    impl Paginated for DummyRequest {
+        fn set_pagination(&mut self, cursor: Option<Cursor>) { self.after = cursor }
+    }
+
+    /// A [`crate::HttpClient`] that hands out a fixed sequence of raw JSON bodies, one per call.
+    struct MockHttpClient {
+        pages: Mutex<VecDeque<&'static str>>,
+    }
+
+    impl<'a> crate::HttpClient<'a> for MockHttpClient {
+        type Error = std::convert::Infallible;
+
+        fn req(
+            &'a self,
+            _request: http::Request<Vec<u8>>,
+        ) -> futures::future::BoxFuture<'a, Result<http::Response<Vec<u8>>, Self::Error>> {
+            let body = self
+                .pages
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or(r#"{"data":[]}"#)
+                .as_bytes()
+                .to_vec();
+            Box::pin(async move { Ok(http::Response::builder().body(body).unwrap()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn make_stream_follows_cursor_until_exhausted() {
+        use futures::TryStreamExt;
+
+        let client = HelixClient::new(MockHttpClient {
+            pages: Mutex::new(VecDeque::from([
+                r#"{"data":[1,2],"pagination":{"cursor":"abc"}}"#,
+                r#"{"data":[3],"pagination":{"cursor":null}}"#,
+            ])),
+        });
+        let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+        let token =
+            twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None)
+                .await
+                .unwrap();
+
+        let items: Vec<i32> = make_stream(DummyRequest::default(), &token, &client, VecDeque::from)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn make_stream_stops_on_empty_page() {
+        use futures::TryStreamExt;
+
+        let client = HelixClient::new(MockHttpClient {
+            pages: Mutex::new(VecDeque::from([r#"{"data":[],"pagination":{"cursor":"abc"}}"#])),
+        });
+        let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+        let token =
+            twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None)
+                .await
+                .unwrap();
+
+        let items: Vec<i32> = make_stream(DummyRequest::default(), &token, &client, VecDeque::from)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+    }
+}
+
+/// Could not construct a valid [`http::Uri`]
+#[derive(thiserror::Error, Debug)]
+pub enum InvalidUriError {
+    /// Could not serialize request into query parameters
+    #[error(transparent)]
+    SerializeError(#[from] serde_urlencoded::ser::Error),
+    /// Could not parse as a [`http::Uri`]
+    #[error(transparent)]
+    UriError(#[from] http::uri::InvalidUri),
+}
+
+/// Errors that can happen when creating a [`http::Request`] from a [`RequestGet`].
+#[derive(thiserror::Error, Debug)]
+pub enum CreateRequestError {
+    /// Could not construct a valid uri
+    #[error(transparent)]
+    InvalidUriError(#[from] InvalidUriError),
+    /// Could not construct the [`http::Request`]
+    #[error(transparent)]
+    HttpError(#[from] http::Error),
+}
+
+/// Errors that can happen when parsing a [`http::Response`] from Helix.
+#[derive(thiserror::Error, Debug)]
+pub enum HelixRequestGetError {
+    /// response was not valid utf8
+    #[error("response was not valid utf8: {1} at {2}")]
+    Utf8Error(Vec<u8>, std::str::Utf8Error, http::Uri),
+    /// deserialization failed
+    #[error("deserialization failed: {1} at {2}")]
+    DeserializeError(String, serde_json::Error, http::Uri),
+}
+
+/// Errors that can happen with a request, wrapping request creation, the http client and
+/// response parsing.
+#[derive(thiserror::Error, Debug)]
+pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
+    /// Could not create request
+    #[error(transparent)]
+    CreateRequestError(#[from] CreateRequestError),
+    /// Request failed to be sent
+    #[error(transparent)]
+    RequestError(RE),
+    /// Could not parse GET response
+    #[error(transparent)]
+    HelixRequestGetError(#[from] HelixRequestGetError),
+}
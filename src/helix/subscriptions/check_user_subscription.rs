@@ -11,6 +11,7 @@
 //! use twitch_api2::helix::subscriptions::check_user_subscription;
 //! let request = check_user_subscription::CheckUserSubscriptionRequest::builder()
 //!     .broadcaster_id("1234")
+//!     .user_id("5678")
 //!     .build();
 //! ```
 //!
@@ -28,20 +29,84 @@
 //! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
 //! let request = check_user_subscription::CheckUserSubscriptionRequest::builder()
 //!     .broadcaster_id("1234")
+//!     .user_id("5678")
 //!     .build();
-//! let response: check_user_subscription::UserSubscription = client.req_get(request, &token).await?.data;
+//! let response: Vec<check_user_subscription::UserSubscription> = client.req_get(request, &token).await?.data;
 //! # Ok(())
 //! # }
 //! ```
 //!
+//! The broadcaster's subscriber point total, returned alongside `data` by this family of
+//! endpoints, is not part of [`UserSubscription`] itself — read it off the envelope with
+//! [`helix::Response::points`] instead.
+//!
 //! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
-//! and parse the [`http::Response`] with [`CheckUserSubscriptionRequest::parse_response(None, &request.get_uri(), response)`](CheckUserSubscriptionRequest::parse_response)
+//! and parse the [`http::Response`] with [`CheckUserSubscriptionRequest::parse_response(request, &uri, response)`](CheckUserSubscriptionRequest::parse_response)
 
 use std::convert::TryInto;
 
 use super::*;
 use helix::RequestGet;
 
+/// Query Parameters for [Check User Subscription](super::check_user_subscription)
+///
+/// [`check-user-subscription`](https://dev.twitch.tv/docs/api/reference#check-user-subscription)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CheckUserSubscriptionRequest {
+    /// User ID of the broadcaster.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// User ID of the subscriber. Must match the User ID in the Bearer token.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+impl helix::Request for CheckUserSubscriptionRequest {
+    type Response = Vec<UserSubscription>;
+
+    const PATH: &'static str = "subscriptions/user";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadSubscriptions];
+}
+
+impl helix::RequestGet for CheckUserSubscriptionRequest {}
+
+impl CheckUserSubscriptionRequest {
+    /// Parse the response, mapping a `404 Not Found` (the user is not subscribed) to `data: None`
+    /// instead of an error, since [`RequestGet::parse_response`] has no way to special-case a
+    /// status code on its own.
+    pub fn parse_response(
+        request: Self,
+        uri: &http::Uri,
+        response: http::Response<Vec<u8>>,
+    ) -> Result<helix::Response<Self, Option<UserSubscription>>, helix::HelixRequestGetError> {
+        if response.status() == http::StatusCode::NOT_FOUND {
+            return Ok(helix::Response {
+                data: None,
+                pagination: None,
+                total: None,
+                other: serde_json::Map::new(),
+                request,
+            });
+        }
+        let helix::Response {
+            data,
+            pagination,
+            total,
+            other,
+            request,
+        } = request.parse_response(uri, response)?;
+        Ok(helix::Response {
+            data: data.into_iter().next(),
+            pagination,
+            total,
+            other,
+            request,
+        })
+    }
+}
+
 /// Return Values for [Check User Subscription](super::check_user_subscription)
 ///
 /// [`check-user-subscription`](https://dev.twitch.tv/docs/api/reference#check-user-subscription)
@@ -64,3 +129,58 @@ pub struct UserSubscription {
     /// Subscription tier. 1000 is tier 1, 2000 is tier 2, and 3000 is tier 3.
     pub tier: types::SubscriptionTier,
 }
+
+#[test]
+fn test_request_with_points() {
+    use helix::*;
+    let req = CheckUserSubscriptionRequest::builder()
+        .broadcaster_id("1234")
+        .user_id("5678")
+        .build();
+
+    // From twitch docs, with `points` added alongside `data`
+    let data = br#"
+{
+    "data": [
+        {
+            "broadcaster_id": "1234",
+            "broadcaster_login": "twitchdev",
+            "broadcaster_name": "TwitchDev",
+            "is_gift": false,
+            "gifter_login": null,
+            "gifter_name": null,
+            "tier": "1000"
+        }
+    ],
+    "points": 500
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let response = CheckUserSubscriptionRequest::parse_response(req, &uri, http_response).unwrap();
+    assert_eq!(response.points(), Some(500));
+    assert!(response.data.is_some());
+}
+
+#[test]
+fn test_request_not_subscribed() {
+    use helix::*;
+    let req = CheckUserSubscriptionRequest::builder()
+        .broadcaster_id("1234")
+        .user_id("5678")
+        .build();
+
+    let http_response = http::Response::builder()
+        .status(http::StatusCode::NOT_FOUND)
+        .body(br#"{"error":"Not Found","status":404,"message":"user is not subscribed"}"#.to_vec())
+        .unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let response = CheckUserSubscriptionRequest::parse_response(req, &uri, http_response).unwrap();
+    assert_eq!(response.data, None);
+    assert_eq!(response.points(), None);
+    assert!(response.other.is_empty());
+}
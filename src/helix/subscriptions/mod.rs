@@ -0,0 +1,9 @@
+//! Endpoints regarding subscriptions
+
+#[doc(inline)]
+pub use check_user_subscription::UserSubscription;
+
+use crate::{helix, types};
+use serde::{Deserialize, Serialize};
+
+pub mod check_user_subscription;